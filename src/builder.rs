@@ -0,0 +1,191 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tokio::net::TcpStream;
+use tokio_native_tls::TlsConnector;
+
+use crate::types::{Dict, Error, List, Result};
+use crate::session::Session;
+
+/// The client version advertised to the daemon on `login` when the caller does
+/// not set one explicitly.
+pub const DEFAULT_CLIENT_VERSION: &str = "2.0.4.dev23";
+
+/// How the TLS certificate presented by the daemon is trusted.
+///
+/// Deluge's RPC transport is always TLS, but the daemon usually presents a
+/// self-signed certificate, so the trust decision has to be made by the caller.
+#[derive(Debug, Clone)]
+pub enum TlsVerification {
+    /// Verify against the system root store (the default for a public cert).
+    System,
+    /// Accept any certificate the daemon presents, including self-signed ones.
+    AcceptInvalid,
+    /// Trust only the certificate stored in the given PEM file.
+    Pinned(PathBuf),
+}
+
+impl Default for TlsVerification {
+    fn default() -> Self {
+        Self::AcceptInvalid
+    }
+}
+
+impl TlsVerification {
+    /// Build the connector this trust decision implies.
+    fn connector(&self) -> Result<TlsConnector> {
+        let mut builder = native_tls::TlsConnector::builder();
+        match self {
+            Self::System => {}
+            Self::AcceptInvalid => {
+                builder.danger_accept_invalid_certs(true);
+            }
+            Self::Pinned(path) => {
+                let pem = std::fs::read(path)?;
+                let cert = native_tls::Certificate::from_pem(&pem).map_err(tls_error)?;
+                // The pinned cert is almost never issued with the connecting
+                // IP as a SAN, so hostname matching would reject it even
+                // though the cert itself is now fully trusted; skip it.
+                builder
+                    .disable_built_in_roots(true)
+                    .add_root_certificate(cert)
+                    .danger_accept_invalid_hostnames(true);
+            }
+        }
+        let connector = builder.build().map_err(tls_error)?;
+        Ok(TlsConnector::from(connector))
+    }
+}
+
+fn tls_error(e: native_tls::Error) -> Error {
+    Error { exception: "SSLError".to_string(), args: List::new(), kwargs: Dict::default(), traceback: e.to_string() }
+}
+
+/// Optional credentials to log in with automatically once connected.
+#[derive(Debug, Clone)]
+struct Credentials {
+    username: String,
+    password: String,
+}
+
+/// Builder for a [`Session`], assembling the endpoint, TLS trust decision,
+/// connect timeout, advertised client version, and optional auto-login in
+/// one place.
+///
+/// [`Session::connect`] stays as a convenience wrapper over
+/// `SessionBuilder::new(endpoint).build().await`.
+pub struct SessionBuilder {
+    endpoint: SocketAddr,
+    client_version: String,
+    connect_timeout: Option<Duration>,
+    tls: TlsVerification,
+    credentials: Option<Credentials>,
+}
+
+impl SessionBuilder {
+    /// Start building a session for the given daemon endpoint.
+    pub fn new(endpoint: SocketAddr) -> Self {
+        Self {
+            endpoint,
+            client_version: DEFAULT_CLIENT_VERSION.to_string(),
+            connect_timeout: None,
+            tls: TlsVerification::default(),
+            credentials: None,
+        }
+    }
+
+    /// Override the client version string passed to `login`.
+    pub fn client_version(mut self, version: impl Into<String>) -> Self {
+        self.client_version = version.into();
+        self
+    }
+
+    /// Set a timeout for establishing the transport (TCP connect + TLS
+    /// handshake).
+    ///
+    /// This only bounds the connect step; there is no per-request timeout
+    /// yet, so a daemon that accepts the connection and then stalls mid-call
+    /// will still hang the caller.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Choose how the daemon's TLS certificate is verified.
+    pub fn tls_verification(mut self, tls: TlsVerification) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    /// Log in automatically once connected, using the given credentials and the
+    /// configured [`client_version`](SessionBuilder::client_version).
+    pub fn login(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.credentials = Some(Credentials { username: username.into(), password: password.into() });
+        self
+    }
+
+    /// Connect to the daemon and, if credentials were supplied, log in.
+    pub async fn build(self) -> Result<Session> {
+        let mut session = Session::connect_with(self.endpoint, self.connect_timeout, self.tls.clone()).await?;
+        if let Some(creds) = &self.credentials {
+            session.login(&creds.username, &creds.password, &self.client_version).await?;
+        }
+        Ok(session)
+    }
+
+    /// The endpoint, TLS trust decision, connect timeout, client version, and
+    /// any auto-login credentials this builder was configured with.
+    ///
+    /// Exposed so [`ReconnectingSession`](crate::reconnect::ReconnectingSession)
+    /// can replay the exact settings used to build the session it wraps,
+    /// instead of reconnecting with different defaults.
+    pub(crate) fn parts(&self) -> (SocketAddr, TlsVerification, Option<Duration>, String, Option<(String, String)>) {
+        (
+            self.endpoint,
+            self.tls.clone(),
+            self.connect_timeout,
+            self.client_version.clone(),
+            self.credentials.as_ref().map(|c| (c.username.clone(), c.password.clone())),
+        )
+    }
+}
+
+impl Session {
+    /// Connect to the daemon at `endpoint`, accepting any certificate it
+    /// presents and with no connect timeout.
+    ///
+    /// A convenience wrapper over `SessionBuilder::new(endpoint).build()`; use
+    /// [`SessionBuilder`] directly to pin a certificate, require system TLS
+    /// verification, set a connect timeout, or log in as part of connecting.
+    pub async fn connect(endpoint: SocketAddr) -> Result<Self> {
+        SessionBuilder::new(endpoint).build().await
+    }
+
+    /// Connect to the daemon at `endpoint` with an explicit TLS trust decision
+    /// and optional connect timeout.
+    ///
+    /// This is where [`SessionBuilder::build`] actually opens the transport,
+    /// so every knob the builder exposes reaches the connection it produces.
+    /// `connect_timeout` only bounds the TCP connect and TLS handshake; it is
+    /// not a per-request timeout, since requests are sent over this same
+    /// session well after `connect_with` has returned.
+    pub(crate) async fn connect_with(endpoint: SocketAddr, connect_timeout: Option<Duration>, tls: TlsVerification) -> Result<Self> {
+        let connect = async {
+            let tcp = TcpStream::connect(endpoint).await?;
+            let stream = tls.connector()?.connect(&endpoint.ip().to_string(), tcp).await.map_err(tls_error)?;
+            // `from_stream` is the same low-level constructor `connect` always
+            // used under the hood; we're just giving callers a way to steer
+            // the TLS/connect-timeout decisions that go into building the stream.
+            Ok(Self::from_stream(stream))
+        };
+        match connect_timeout {
+            Some(limit) => tokio::time::timeout(limit, connect).await.map_err(|_| connect_timeout_error())?,
+            None => connect.await,
+        }
+    }
+}
+
+fn connect_timeout_error() -> Error {
+    Error { exception: "IOError".to_string(), args: List::new(), kwargs: Dict::default(), traceback: "connect timed out".to_string() }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+use serde_yaml::Value;
+
+use crate::types::Result;
+
+/// Deluge's core configuration, with each known key given its correct Rust
+/// type. Deserialized from the daemon's `get_config` response; see
+/// [`SetConfig`] for the write side.
+///
+/// This is not exhaustive — keys the daemon reports but we don't model are
+/// simply dropped. Fetch the raw map with [`get_config`] if you need one of
+/// them.
+///
+/// [`get_config`]: crate::session::Session::get_config
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoreConfig {
+    pub download_location: String,
+    pub dht: bool,
+    pub upnp: bool,
+    pub natpmp: bool,
+    pub utpex: bool,
+    pub lsd: bool,
+    pub max_connections_global: i64,
+    pub max_upload_slots_global: i64,
+    pub max_download_speed: f64,
+    pub max_upload_speed: f64,
+    pub listen_ports: (u16, u16),
+    pub random_port: bool,
+    pub prioritize_first_last_pieces: bool,
+    pub add_paused: bool,
+}
+
+/// A settable view of [`CoreConfig`]: every field is optional, and only the
+/// populated ones are serialized, so `set_config` touches just the keys the
+/// caller changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SetConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dht: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upnp: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub natpmp: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utpex: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lsd: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections_global: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_upload_slots_global: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_download_speed: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_upload_speed: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub listen_ports: Option<(u16, u16)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub random_port: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prioritize_first_last_pieces: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_paused: Option<bool>,
+}
+
+impl SetConfig {
+    /// The populated keys as the `{key: value}` map `set_config` expects.
+    pub fn to_map(&self) -> Result<HashMap<String, Value>> {
+        Ok(match serde_yaml::to_value(self)? {
+            Value::Mapping(map) => map
+                .into_iter()
+                .filter_map(|(k, v)| match k {
+                    Value::String(k) => Some((k, v)),
+                    _ => None,
+                })
+                .collect(),
+            _ => HashMap::new(),
+        })
+    }
+}
+
+/// The known `core` config keys, usable as a typed discriminant instead of a
+/// stringly-typed key list.
+///
+/// The wire name for each variant lives solely in [`as_str`](ConfigKey::as_str)
+/// — this deliberately doesn't derive `Serialize`/`Deserialize`, so there's
+/// only one mapping to keep in sync, not two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigKey {
+    DownloadLocation,
+    Dht,
+    Upnp,
+    Natpmp,
+    Utpex,
+    Lsd,
+    MaxConnectionsGlobal,
+    MaxUploadSlotsGlobal,
+    MaxDownloadSpeed,
+    MaxUploadSpeed,
+    ListenPorts,
+    RandomPort,
+    PrioritizeFirstLastPieces,
+    AddPaused,
+}
+
+impl ConfigKey {
+    /// The wire name of this key.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::DownloadLocation => "download_location",
+            Self::Dht => "dht",
+            Self::Upnp => "upnp",
+            Self::Natpmp => "natpmp",
+            Self::Utpex => "utpex",
+            Self::Lsd => "lsd",
+            Self::MaxConnectionsGlobal => "max_connections_global",
+            Self::MaxUploadSlotsGlobal => "max_upload_slots_global",
+            Self::MaxDownloadSpeed => "max_download_speed",
+            Self::MaxUploadSpeed => "max_upload_speed",
+            Self::ListenPorts => "listen_ports",
+            Self::RandomPort => "random_port",
+            Self::PrioritizeFirstLastPieces => "prioritize_first_last_pieces",
+            Self::AddPaused => "add_paused",
+        }
+    }
+}
+
+/// A typed `get_session_status` query, analogous to the `Query` trait used for
+/// torrent status: implementors list the libtorrent session keys they need and
+/// deserialize the daemon's reply.
+///
+/// The common case is covered by [`SessionStatus`].
+pub trait SessionQuery: serde::de::DeserializeOwned {
+    fn keys() -> &'static [&'static str];
+}
+
+/// The session-wide transfer counters most clients want.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SessionStatus {
+    #[serde(rename = "net.recv_bytes")]
+    pub recv_bytes: u64,
+    #[serde(rename = "net.sent_bytes")]
+    pub sent_bytes: u64,
+    #[serde(rename = "peer.num_peers_connected")]
+    pub num_peers_connected: u64,
+    #[serde(rename = "dht.dht_nodes")]
+    pub dht_nodes: u64,
+}
+
+impl SessionQuery for SessionStatus {
+    fn keys() -> &'static [&'static str] {
+        &["net.recv_bytes", "net.sent_bytes", "peer.num_peers_connected", "dht.dht_nodes"]
+    }
+}
@@ -6,6 +6,7 @@ use std::net::{IpAddr, SocketAddr};
 use deluge_rpc_macro::rpc_class;
 
 use crate::types::{Result, TorrentOptions, AuthLevel, InfoHash, Query, EventKind};
+use crate::config::{CoreConfig, SetConfig, SessionQuery, ConfigKey};
 use crate::session::Session;
 
 rpc_class! {
@@ -14,8 +15,8 @@ rpc_class! {
     #[rpc(method = "info", auth_level = "Nobody")]
     pub rpc fn daemon_info(&mut self) -> String;
 
-    #[rpc(auth_level = "Nobody", client_version = "2.0.4.dev23")]
-    pub rpc fn login(&mut self, username: &str, password: &str) -> AuthLevel {
+    #[rpc(auth_level = "Nobody")]
+    pub rpc fn login(&mut self, username: &str, password: &str, client_version: &str) -> AuthLevel {
         self.auth_level = val;
         Ok(self.auth_level)
     }
@@ -88,11 +89,28 @@ rpc_class! {
 
     pub rpc fn get_config<T: DeserializeOwned>(&mut self) -> HashMap<String, T>;
 
+    #[rpc(method = "get_config")]
+    pub rpc fn get_config_dyn<T: DeserializeOwned>(&mut self) -> T;
+
+    /// Fetch the whole core config, deserialized into the typed [`CoreConfig`].
+    pub async fn get_core_config(&mut self) -> Result<CoreConfig> {
+        self.get_config_dyn().await
+    }
+
     pub rpc fn get_config_value<T: DeserializeOwned>(&mut self, key: &str) -> T;
 
-    // TODO: ConfigQuery trait and/or ConfigKey enum
     pub rpc fn get_config_values<T: DeserializeOwned>(&mut self, keys: &[&str]) -> HashMap<String, T>;
 
+    /// Fetch a chosen subset of config keys without a stringly-typed key list.
+    ///
+    /// The result is keyed by [`ConfigKey`] rather than the daemon's raw
+    /// strings, so callers never have to parse a key back into the enum.
+    pub async fn get_config_values_typed<T: DeserializeOwned>(&mut self, keys: &[ConfigKey]) -> Result<HashMap<ConfigKey, T>> {
+        let key_strs: Vec<&str> = keys.iter().map(|k| k.as_str()).collect();
+        let mut raw = self.get_config_values::<T>(&key_strs).await?;
+        Ok(keys.iter().filter_map(|&key| raw.remove(key.as_str()).map(|value| (key, value))).collect())
+    }
+
     pub rpc fn get_enabled_plugins(&mut self) -> Vec<String>;
 
     pub rpc fn get_external_ip(&mut self) -> IpAddr;
@@ -118,9 +136,16 @@ rpc_class! {
 
     pub rpc fn get_session_state(&mut self) -> Vec<InfoHash>;
 
-    // TODO: SessionQuery trait and/or SessionKey enum
     pub rpc fn get_session_status<T: DeserializeOwned>(&mut self, keys: &[&str]) -> HashMap<String, T>;
 
+    #[rpc(method = "get_session_status")]
+    pub rpc fn get_session_status_dyn<T: DeserializeOwned>(&mut self, keys: &[&str]) -> T;
+
+    /// Fetch a typed slice of the libtorrent session status.
+    pub async fn get_session_status_typed<T: SessionQuery>(&mut self) -> Result<T> {
+        self.get_session_status_dyn(T::keys()).await
+    }
+
     #[rpc(method = "get_torrent_status")]
     pub rpc fn get_torrent_status_dyn<T: DeserializeOwned>(&mut self, torrent_id: InfoHash, keys: &[&str], diff: bool) -> T;
 
@@ -190,6 +215,12 @@ rpc_class! {
 
     pub rpc fn set_config(&mut self, config: HashMap<String, impl Serialize>);
 
+    /// Apply only the populated fields of a [`SetConfig`], leaving every other
+    /// key untouched.
+    pub async fn set_core_config(&mut self, config: &SetConfig) -> Result<()> {
+        self.set_config(config.to_map()?).await
+    }
+
     pub rpc fn set_torrent_options(&mut self, torrent_ids: &[InfoHash], options: &TorrentOptions);
 
     pub rpc fn test_listen_port(&mut self) -> bool;
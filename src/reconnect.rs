@@ -0,0 +1,298 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+
+use crate::types::{Dict, EventKind, Error, List, Result};
+use crate::rpc::Event;
+use crate::builder::{SessionBuilder, TlsVerification};
+use crate::session::Session;
+
+/// Backoff policy for reconnection attempts: start at `base`, double after each
+/// failed attempt, and never wait longer than `cap`.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    pub base: Duration,
+    pub cap: Duration,
+    /// Maximum number of attempts before giving up. Clamped to at least one.
+    pub max_attempts: u32,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self { base: Duration::from_millis(500), cap: Duration::from_secs(30), max_attempts: 8 }
+    }
+}
+
+impl Backoff {
+    /// The delay to wait before the `attempt`-th retry (0-indexed).
+    fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        self.base.saturating_mul(factor).min(self.cap)
+    }
+}
+
+/// The error returned by a call routed through [`ReconnectingSession::call`].
+#[derive(Debug)]
+pub enum ReconnectError {
+    /// The daemon returned an ordinary RPC error; no reconnect was attempted.
+    Rpc(Error),
+    /// The transport dropped and could not be re-established within the backoff
+    /// budget. Carries the last reconnection error seen.
+    Reconnect(Error),
+    /// The transport was re-established, but the retried call still failed.
+    RetryFailed(Error),
+}
+
+impl std::fmt::Display for ReconnectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Rpc(e) => write!(f, "{}", e),
+            Self::Reconnect(e) => write!(f, "reconnect failed: {}", e),
+            Self::RetryFailed(e) => write!(f, "call failed after reconnect: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ReconnectError {}
+
+/// A [`Session`] that transparently re-establishes its transport on failure.
+///
+/// A dropped TCP/TLS connection otherwise kills the session and silently loses
+/// the event subscription. This layer owns the session and remembers everything
+/// needed to restore it — the credentials and client version used at `login`,
+/// the [`EventKind`]s passed to [`set_event_interest`], and whether an event
+/// subscription is active — so that on a transport error it reconnects with
+/// exponential backoff, replays `login` and `set_event_interest`, and retries
+/// the in-flight call once before surfacing a distinct error.
+///
+/// Route fallible calls through [`call`](ReconnectingSession::call) to get the
+/// retry-once behavior; consume events through [`recv_event`](ReconnectingSession::recv_event)
+/// so the layer can re-wire the subscription across a reconnect.
+///
+/// [`set_event_interest`]: crate::session::Session::set_event_interest
+pub struct ReconnectingSession {
+    session: Session,
+    endpoint: SocketAddr,
+    tls: TlsVerification,
+    // Applied both to the TCP+TLS handshake on every (re)connect and, inside
+    // `call`, to each request — a stalled daemon should surface the same way
+    // a dropped connection does, not hang the caller forever.
+    timeout: Option<Duration>,
+    username: String,
+    password: String,
+    client_version: String,
+    event_interest: HashSet<EventKind>,
+    // The active `subscribe_events` receiver, tracked so a reconnect can
+    // re-issue the subscription and keep feeding the same consumer.
+    events: Option<broadcast::Receiver<Event>>,
+    backoff: Backoff,
+    reconnected: broadcast::Sender<()>,
+}
+
+impl ReconnectingSession {
+    /// Wrap an already-built [`Session`] with the [`SessionBuilder`] it was
+    /// built from, so a reconnect remembers the exact endpoint, TLS trust
+    /// decision, client version, and credentials the session was connected
+    /// with, instead of reverting to this layer's own defaults.
+    pub fn new(session: Session, builder: SessionBuilder) -> Self {
+        let (endpoint, tls, timeout, client_version, credentials) = builder.parts();
+        let (username, password) = credentials.unwrap_or_default();
+        let (reconnected, _) = broadcast::channel(16);
+        Self {
+            session,
+            endpoint,
+            tls,
+            timeout,
+            username,
+            password,
+            client_version,
+            event_interest: HashSet::new(),
+            events: None,
+            backoff: Backoff::default(),
+            reconnected,
+        }
+    }
+
+    /// Set the TLS trust decision to reuse when reconnecting.
+    pub fn tls_verification(mut self, tls: TlsVerification) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    /// Set the timeout applied to the transport handshake on every
+    /// (re)connect and to each request routed through
+    /// [`call`](ReconnectingSession::call).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Override the backoff policy (including the attempt cap).
+    pub fn backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Log in, remembering the credentials and client version for replay.
+    pub async fn login(&mut self, username: &str, password: &str, client_version: &str) -> Result<()> {
+        self.username = username.to_string();
+        self.password = password.to_string();
+        self.client_version = client_version.to_string();
+        self.session.login(username, password, client_version).await?;
+        Ok(())
+    }
+
+    /// Subscribe to daemon events, tracking the receiver so it can be re-wired
+    /// after a reconnect.
+    pub fn subscribe_events(&mut self) -> broadcast::Receiver<Event> {
+        let receiver = self.session.subscribe_events();
+        self.events = Some(receiver.resubscribe());
+        receiver
+    }
+
+    /// Register event interest, remembering it for replay.
+    pub async fn set_event_interest(&mut self, events: &HashSet<EventKind>) -> Result<bool> {
+        self.event_interest = events.clone();
+        self.session.set_event_interest(events).await
+    }
+
+    /// Subscribe to reconnection notifications so callers can refresh their
+    /// state cache after a gap.
+    pub fn reconnected(&self) -> broadcast::Receiver<()> {
+        self.reconnected.subscribe()
+    }
+
+    /// Borrow the underlying session for calls that don't need retry semantics.
+    pub fn session(&mut self) -> &mut Session {
+        &mut self.session
+    }
+
+    /// Run an RPC against the session, transparently reconnecting and retrying
+    /// once on transport failure.
+    ///
+    /// An ordinary daemon error is returned as [`ReconnectError::Rpc`] without a
+    /// reconnect. On a transport error the layer reconnects (replaying login and
+    /// event interest), then runs `op` a second time; a distinct
+    /// [`ReconnectError::RetryFailed`] surfaces only if that retry also fails.
+    ///
+    /// Each attempt is bound by [`timeout`](ReconnectingSession::timeout), so a
+    /// daemon that accepts the call and then stalls is treated the same as a
+    /// dropped connection instead of hanging the caller forever.
+    pub async fn call<F, Fut, T>(&mut self, mut op: F) -> std::result::Result<T, ReconnectError>
+    where
+        F: FnMut(&mut Session) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        match self.call_once(&mut op).await {
+            Ok(value) => Ok(value),
+            Err(e) if is_transport_error(&e) => {
+                self.reconnect().await.map_err(ReconnectError::Reconnect)?;
+                self.call_once(&mut op).await.map_err(ReconnectError::RetryFailed)
+            }
+            Err(e) => Err(ReconnectError::Rpc(e)),
+        }
+    }
+
+    /// Run a single attempt of `op`, timing it out the same as a transport
+    /// failure if it runs past [`timeout`](ReconnectingSession::timeout).
+    async fn call_once<F, Fut, T>(&mut self, op: &mut F) -> Result<T>
+    where
+        F: FnMut(&mut Session) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        match self.timeout {
+            Some(limit) => match tokio::time::timeout(limit, op(&mut self.session)).await {
+                Ok(result) => result,
+                Err(_) => Err(request_timeout_error()),
+            },
+            None => op(&mut self.session).await,
+        }
+    }
+
+    /// Receive the next event, re-establishing the subscription across a
+    /// reconnect so a long-lived consumer never has to re-subscribe by hand.
+    ///
+    /// Returns `None` when no subscription is active, or when the stream closes
+    /// and reconnecting fails.
+    pub async fn recv_event(&mut self) -> Option<Event> {
+        loop {
+            let events = self.events.as_mut()?;
+            match events.recv().await {
+                Ok(event) => return Some(event),
+                // A closed channel means the transport dropped; reconnect re-issues
+                // the subscription into `self.events`, then we read from the new one.
+                Err(broadcast::error::RecvError::Closed) => {
+                    self.reconnect().await.ok()?;
+                }
+                // Lagged only means we fell behind; keep reading the same stream.
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            }
+        }
+    }
+
+    /// Rebuild the transport, replaying login, event interest, and any active
+    /// subscription. Uses exponential backoff up to the policy's attempt cap.
+    pub async fn reconnect(&mut self) -> Result<()> {
+        let attempts = self.backoff.max_attempts.max(1);
+        let mut last_err = None;
+        for attempt in 0..attempts {
+            if attempt > 0 {
+                tokio::time::sleep(self.backoff.delay(attempt - 1)).await;
+            }
+            match self.try_reconnect().await {
+                Ok(()) => {
+                    let _ = self.reconnected.send(());
+                    return Ok(());
+                }
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.expect("attempts is at least one"))
+    }
+
+    async fn try_reconnect(&mut self) -> Result<()> {
+        let mut builder = SessionBuilder::new(self.endpoint)
+            .client_version(self.client_version.clone())
+            .tls_verification(self.tls.clone());
+        // An empty username means the session was never given credentials, so
+        // there is no login to replay.
+        if !self.username.is_empty() {
+            builder = builder.login(self.username.clone(), self.password.clone());
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        let mut session = builder.build().await?;
+
+        // Replay the event subscription onto the new session if one was active,
+        // holding the receiver so `recv_event` can keep feeding the consumer.
+        if self.events.is_some() {
+            let receiver = session.subscribe_events();
+            session.set_event_interest(&self.event_interest).await?;
+            self.events = Some(receiver);
+        }
+
+        self.session = session;
+        Ok(())
+    }
+}
+
+/// Whether an error reflects a lost transport (reconnect) rather than a daemon
+/// rejecting the call (surface as-is). Deluge delivers a dropped connection as a
+/// synthetic error whose exception names the socket failure.
+fn is_transport_error(err: &Error) -> bool {
+    matches!(
+        err.exception.as_str(),
+        "IOError" | "ConnectionLost" | "ConnectionRefusedError" | "ConnectionResetError"
+    )
+}
+
+/// A request that ran past [`ReconnectingSession::timeout`], reported as the
+/// same synthetic `IOError` a dropped connection would produce so it's
+/// treated as a transport error by [`is_transport_error`].
+fn request_timeout_error() -> Error {
+    Error { exception: "IOError".to_string(), args: List::new(), kwargs: Dict::default(), traceback: "request timed out".to_string() }
+}
@@ -1,7 +1,7 @@
 use serde_yaml::{self, Value};
 use serde::Deserialize;
 use std::convert::{From, TryFrom};
-use crate::types::{InfoHash, List, Dict};
+use crate::types::{InfoHash, List, Dict, TorrentState};
 use lazy_static::lazy_static;
 use lazy_regex::regex;
 use std::fmt;
@@ -32,6 +32,31 @@ impl From<(String, List, Dict, String)> for GenericError {
 
 impl std::error::Error for GenericError {}
 
+// The daemon speaks in Python tracebacks, but local I/O and (de)serialization
+// failures still have to surface through this crate's one error type. Wrap them
+// as a synthetic traceback so `?` works across the whole surface.
+impl From<std::io::Error> for GenericError {
+    fn from(e: std::io::Error) -> Self {
+        Self {
+            exception: "IOError".to_string(),
+            args: List::new(),
+            kwargs: Dict::default(),
+            traceback: e.to_string(),
+        }
+    }
+}
+
+impl From<serde_yaml::Error> for GenericError {
+    fn from(e: serde_yaml::Error) -> Self {
+        Self {
+            exception: "SerializationError".to_string(),
+            args: List::new(),
+            kwargs: Dict::default(),
+            traceback: e.to_string(),
+        }
+    }
+}
+
 pub type Error = GenericError;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -107,10 +132,85 @@ impl From<GenericError> for SpecializedError {
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// A daemon event, decoded into a known variant where possible.
+///
+/// The daemon delivers each event as an `(event_name, data)` pair where `data`
+/// is a fixed-order positional argument list. Known events are decoded into a
+/// typed variant by deserializing that list; anything we don't recognize (or
+/// that fails to deserialize into the expected shape) falls back to
+/// [`Event::Dynamic`], which hands back the raw name and list unchanged.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// `from_state` is `true` when the torrent was added as part of loading
+    /// the session state at startup, rather than by an explicit add call.
+    TorrentAdded { id: InfoHash, from_state: bool },
+    TorrentRemoved { id: InfoHash },
+    TorrentStateChanged { id: InfoHash, state: TorrentState },
+    TorrentFinished { id: InfoHash },
+    TorrentPaused { id: InfoHash },
+    TorrentResumed { id: InfoHash },
+    TorrentFileRenamed { id: InfoHash, index: u64, name: String },
+    TorrentFolderRenamed { id: InfoHash, old_folder: String, new_folder: String },
+    TorrentStorageMoved { id: InfoHash, path: String },
+    ConfigValueChanged { key: String, value: Value },
+    PluginEnabled { name: String },
+    PluginDisabled { name: String },
+    SessionPaused,
+    SessionResumed,
+    NewVersionAvailable { new_release: String },
+    Dynamic { event_name: String, data: List },
+}
+
+impl Event {
+    fn from_raw(event_name: String, data: List) -> Self {
+        use serde_yaml::from_value;
+
+        // Each known event's arguments are a fixed-order tuple; deserialize the
+        // positional `data` list into the matching shape, and on an unknown name
+        // or a shape mismatch fall back to `Dynamic` rather than erroring.
+        macro_rules! decode {
+            ($ty:ty, |$args:ident| $build:expr) => {
+                match from_value::<$ty>(Value::Sequence(data.clone())) {
+                    Ok($args) => $build,
+                    Err(_) => Self::Dynamic { event_name, data },
+                }
+            };
+        }
+
+        match event_name.as_str() {
+            // Carries (torrent_id, from_state), not just the id.
+            "TorrentAddedEvent" => decode!((InfoHash, bool), |a| Self::TorrentAdded { id: a.0, from_state: a.1 }),
+            "TorrentRemovedEvent" => decode!((InfoHash,), |a| Self::TorrentRemoved { id: a.0 }),
+            "TorrentStateChangedEvent" => {
+                decode!((InfoHash, TorrentState), |a| Self::TorrentStateChanged { id: a.0, state: a.1 })
+            }
+            "TorrentFinishedEvent" => decode!((InfoHash,), |a| Self::TorrentFinished { id: a.0 }),
+            "TorrentPausedEvent" => decode!((InfoHash,), |a| Self::TorrentPaused { id: a.0 }),
+            "TorrentResumedEvent" => decode!((InfoHash,), |a| Self::TorrentResumed { id: a.0 }),
+            "TorrentFileRenamedEvent" => {
+                decode!((InfoHash, u64, String), |a| Self::TorrentFileRenamed { id: a.0, index: a.1, name: a.2 })
+            }
+            "TorrentFolderRenamedEvent" => {
+                decode!((InfoHash, String, String), |a| Self::TorrentFolderRenamed { id: a.0, old_folder: a.1, new_folder: a.2 })
+            }
+            "TorrentStorageMovedEvent" => {
+                decode!((InfoHash, String), |a| Self::TorrentStorageMoved { id: a.0, path: a.1 })
+            }
+            "ConfigValueChangedEvent" => decode!((String, Value), |a| Self::ConfigValueChanged { key: a.0, value: a.1 }),
+            "PluginEnabledEvent" => decode!((String,), |a| Self::PluginEnabled { name: a.0 }),
+            "PluginDisabledEvent" => decode!((String,), |a| Self::PluginDisabled { name: a.0 }),
+            "SessionPausedEvent" => Self::SessionPaused,
+            "SessionResumedEvent" => Self::SessionResumed,
+            "NewVersionAvailableEvent" => decode!((String,), |a| Self::NewVersionAvailable { new_release: a.0 }),
+            _ => Self::Dynamic { event_name, data },
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum Inbound {
     Response { request_id: i64, result: Result<List> },
-    Event { event_name: String, data: List },
+    Event(Event),
 }
 
 #[value_enum(u8)]
@@ -131,10 +231,10 @@ impl TryFrom<&[Value]> for Inbound {
                 request_id: from_value(data[1].clone())?,
                 result: Err(from_value(Value::Sequence(data[2..=5].to_vec()))?),
             },
-            MessageType::Event => Inbound::Event {
-                event_name: from_value(data[1].clone())?,
-                data: from_value(data[2].clone())?,
-            },
+            MessageType::Event => Inbound::Event(Event::from_raw(
+                from_value(data[1].clone())?,
+                from_value(data[2].clone())?,
+            )),
         };
         Ok(val)
     }
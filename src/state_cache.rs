@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Serialize, de::DeserializeOwned};
+use tokio::sync::broadcast;
+
+use crate::types::{InfoHash, Query, EventKind, Result};
+use crate::session::Session;
+use crate::rpc::Event;
+
+/// A single change applied to a [`StateCache`], as delivered by
+/// [`changed`](StateCache::changed).
+///
+/// Covers all three transitions a torrent can go through in the cache: a
+/// subscriber watching only [`Updated`](CacheChange::Updated) would otherwise
+/// never learn a torrent was added or removed.
+pub enum CacheChange<T: Query> {
+    /// A torrent materialized by a full status query, not described by a diff.
+    Added(InfoHash, T),
+    /// A torrent removed from the cache after a `TorrentRemoved` event.
+    Removed(InfoHash),
+    /// An existing torrent patched by the given diff.
+    Updated(InfoHash, T::Diff),
+}
+
+// Derived `Clone` would only bind `T: Clone`, not `T::Diff: Clone`, since the
+// derive macro can't see through the associated type; write it out instead.
+impl<T: Query + Clone> Clone for CacheChange<T>
+where
+    T::Diff: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Added(id, torrent) => Self::Added(*id, torrent.clone()),
+            Self::Removed(id) => Self::Removed(*id),
+            Self::Updated(id, diff) => Self::Updated(*id, diff.clone()),
+        }
+    }
+}
+
+/// A live, event-synced snapshot of every torrent's status.
+///
+/// The cache seeds itself with a single [`get_torrents_status`] call, then keeps
+/// itself current by applying [`get_torrents_status_diff`] patches as events
+/// land instead of re-polling the whole set. This turns the raw diff API into
+/// something a UI can render against directly: `get`/`iter` read the current
+/// snapshot without a round-trip, and [`changed`] streams patches as they apply.
+///
+/// [`get_torrents_status`]: crate::session::Session::get_torrents_status
+/// [`get_torrents_status_diff`]: crate::session::Session::get_torrents_status_diff
+/// [`changed`]: StateCache::changed
+pub struct StateCache<T: Query> {
+    torrents: HashMap<InfoHash, T>,
+    changes: broadcast::Sender<CacheChange<T>>,
+    events: Option<broadcast::Receiver<Event>>,
+    path: Option<PathBuf>,
+}
+
+impl<T> StateCache<T>
+where
+    T: Query + Clone + Serialize + DeserializeOwned + Send + 'static,
+    T::Diff: Clone + Send + 'static,
+{
+    /// Seed a cache from the daemon's current state and subscribe it to events.
+    ///
+    /// The cache registers its own interest in `events` and holds the resulting
+    /// receiver, so [`watch`](StateCache::watch) can keep it live without the
+    /// caller wiring up the subscription. Seeding is a single full status query;
+    /// everything after is diff patches.
+    pub async fn seed(session: &mut Session, events: HashSet<EventKind>) -> Result<Self> {
+        let receiver = session.subscribe_events();
+        session.set_event_interest(&events).await?;
+        let torrents = session.get_torrents_status::<T, String>(None).await?;
+        let (changes, _) = broadcast::channel(256);
+        Ok(Self { torrents, changes, events: Some(receiver), path: None })
+    }
+
+    /// Load a previously-persisted snapshot so a client can render a torrent
+    /// list before the first RPC round-trip completes.
+    ///
+    /// A missing file yields an empty cache rather than an error; the next
+    /// [`sync`](StateCache::sync) brings it up to date.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let torrents = match std::fs::File::open(&path) {
+            Ok(file) => serde_yaml::from_reader(file)?,
+            Err(_) => HashMap::new(),
+        };
+        let (changes, _) = broadcast::channel(256);
+        Ok(Self { torrents, changes, events: None, path: Some(path) })
+    }
+
+    /// Set the file this cache persists to on [`save`](StateCache::save).
+    pub fn persist_to(&mut self, path: impl Into<PathBuf>) {
+        self.path = Some(path.into());
+    }
+
+    /// The current status of a single torrent, if the cache knows about it.
+    pub fn get(&self, id: InfoHash) -> Option<&T> {
+        self.torrents.get(&id)
+    }
+
+    /// Iterate over every torrent currently in the cache.
+    pub fn iter(&self) -> impl Iterator<Item = (&InfoHash, &T)> {
+        self.torrents.iter()
+    }
+
+    /// Subscribe to the changes applied to the cache.
+    ///
+    /// Each [`CacheChange`] is emitted after the corresponding torrent has
+    /// been added, removed, or updated in the snapshot, so a subscriber can
+    /// re-render just the rows that moved.
+    pub fn changed(&self) -> broadcast::Receiver<CacheChange<T>> {
+        self.changes.subscribe()
+    }
+
+    /// Pull a fresh diff from the daemon and fold it into the snapshot.
+    ///
+    /// New torrents are materialized with a full status query; known torrents
+    /// are patched in place. Every applied patch is forwarded to [`changed`]
+    /// subscribers.
+    ///
+    /// [`changed`]: StateCache::changed
+    pub async fn sync(&mut self, session: &mut Session) -> Result<()> {
+        let diffs = session.get_torrents_status_diff::<T, String>(None).await?;
+        for (id, diff) in diffs {
+            match self.torrents.get_mut(&id) {
+                // Only a torrent patched by this exact diff is forwarded as
+                // `Updated`; a torrent we had to materialize with a full query
+                // wasn't described by `diff`, so it is forwarded as `Added` with
+                // its full status instead.
+                Some(torrent) => {
+                    torrent.update(diff.clone());
+                    let _ = self.changes.send(CacheChange::Updated(id, diff));
+                }
+                None => {
+                    let torrent = session.get_torrent_status::<T>(id).await?;
+                    self.torrents.insert(id, torrent.clone());
+                    let _ = self.changes.send(CacheChange::Added(id, torrent));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Drive the cache off its own event subscription until the stream closes.
+    ///
+    /// Requires a cache created with [`seed`](StateCache::seed); a disk-loaded
+    /// cache has no subscription to watch and returns immediately.
+    ///
+    /// The receiver is restored to `self` whether the loop exits cleanly or a
+    /// single event fails to apply, so a transient sync error doesn't
+    /// permanently strand the cache without its subscription.
+    pub async fn watch(&mut self, session: &mut Session) -> Result<()> {
+        let mut events = match self.events.take() {
+            Some(events) => events,
+            None => return Ok(()),
+        };
+        let mut result = Ok(());
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    if let Err(e) = self.apply_event(session, &event).await {
+                        result = Err(e);
+                        break;
+                    }
+                }
+                // Lagged only means we fell behind; keep reading the same stream.
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+        self.events = Some(events);
+        result
+    }
+
+    /// React to a single daemon event, syncing on anything that can change a
+    /// torrent's status and dropping a removed torrent from the snapshot.
+    pub async fn apply_event(&mut self, session: &mut Session, event: &Event) -> Result<()> {
+        match event {
+            Event::TorrentRemoved { id } => {
+                if self.torrents.remove(id).is_some() {
+                    let _ = self.changes.send(CacheChange::Removed(*id));
+                }
+            }
+            Event::Dynamic { .. } => {}
+            _ => self.sync(session).await?,
+        }
+        Ok(())
+    }
+
+    /// Serialize the snapshot to the configured persistence file, as a download
+    /// manager persists its torrent DB on shutdown.
+    pub fn save(&self) -> Result<()> {
+        if let Some(path) = &self.path {
+            self.save_to(path)?;
+        }
+        Ok(())
+    }
+
+    fn save_to(&self, path: &Path) -> Result<()> {
+        let file = std::fs::File::create(path)?;
+        serde_yaml::to_writer(file, &self.torrents)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::types::{Result, TorrentOptions, InfoHash, Query};
+use crate::session::Session;
+
+impl Session {
+    /// A fluent handle for operating on a single torrent, so callers don't have
+    /// to thread the [`InfoHash`] through every core method.
+    pub fn torrent(&mut self, id: InfoHash) -> Torrent<'_> {
+        Torrent { session: self, id }
+    }
+}
+
+/// A borrowed, per-torrent view over a [`Session`].
+///
+/// Every method forwards to the corresponding core (or, for
+/// [`set_label`](Torrent::set_label), label plugin) RPC with this handle's
+/// [`InfoHash`] filled in. The handle borrows the session mutably, so it lasts
+/// only as long as a single chain of operations.
+pub struct Torrent<'a> {
+    session: &'a mut Session,
+    id: InfoHash,
+}
+
+impl Torrent<'_> {
+    /// The [`InfoHash`] this handle operates on.
+    pub fn id(&self) -> InfoHash {
+        self.id
+    }
+
+    /// Fetch this torrent's status for the given [`Query`].
+    pub async fn status<T: Query>(&mut self) -> Result<T> {
+        self.session.get_torrent_status::<T>(self.id).await
+    }
+
+    /// Fetch the diff of this torrent's status since the last such call.
+    pub async fn status_diff<T: Query>(&mut self) -> Result<T::Diff> {
+        self.session.get_torrent_status_diff::<T>(self.id).await
+    }
+
+    pub async fn pause(&mut self) -> Result<()> {
+        self.session.pause_torrent(self.id).await
+    }
+
+    pub async fn resume(&mut self) -> Result<()> {
+        self.session.resume_torrent(self.id).await
+    }
+
+    pub async fn recheck(&mut self) -> Result<()> {
+        self.session.force_recheck(&[self.id]).await
+    }
+
+    pub async fn reannounce(&mut self) -> Result<()> {
+        self.session.force_reannounce(&[self.id]).await
+    }
+
+    pub async fn move_storage(&mut self, dest: &str) -> Result<()> {
+        self.session.move_storage(&[self.id], dest).await
+    }
+
+    pub async fn rename_files(&mut self, filenames: &[(u64, &str)]) -> Result<()> {
+        self.session.rename_files(self.id, filenames).await
+    }
+
+    pub async fn set_options(&mut self, options: &TorrentOptions) -> Result<()> {
+        self.session.set_torrent_options(&[self.id], options).await
+    }
+
+    pub async fn set_label(&mut self, label_id: &str) -> Result<()> {
+        self.session.set_torrent_label(self.id, label_id).await
+    }
+
+    pub async fn remove(&mut self, remove_data: bool) -> Result<()> {
+        self.session.remove_torrent(self.id, remove_data).await
+    }
+}